@@ -1,4 +1,14 @@
 use ego_tree::{NodeId, Tree};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Default UCB1 exploration constant, used unless overridden via
+/// [`MctsBuilder::exploration`].
+const DEFAULT_EXPLORATION: f32 = core::f32::consts::SQRT_2;
 
 pub trait MctsGame: Clone {
     type Action: Copy;
@@ -8,9 +18,34 @@ pub trait MctsGame: Clone {
     fn play(&mut self, action: Self::Action);
     fn player(&self) -> Self::Player;
     fn state(&self, player: Self::Player) -> MctsState;
+
+    /// Key identifying this state for the purposes of the transposition
+    /// table enabled via [`MctsBuilder::use_transposition`]. States reached
+    /// through different move orders but sharing a key accumulate into the
+    /// same statistics, so playouts spent on one transposition benefit every
+    /// other node reached through the same key. Returns `None` by default;
+    /// games that support transpositions must override this to return
+    /// `Some(key)`. Enabling [`MctsBuilder::use_transposition`] on a game
+    /// that still returns `None` here would silently collapse every node in
+    /// the tree into one shared entry, so it panics instead.
+    fn state_key(&self) -> Option<u64> {
+        None
+    }
+
+    /// Heuristic value in `[0, 1]` estimating how good this (possibly
+    /// non-terminal) state is for `player`, used to score a simulation that
+    /// is cut short by [`MctsBuilder::rollout_depth`] before reaching a
+    /// terminal state. The default treats every cut-off rollout as a draw;
+    /// override with a domain-specific static evaluation to cut down on
+    /// variance from playing deep games out to completion.
+    fn evaluate(&self, player: Self::Player) -> f32 {
+        let _ = player;
+        0.5
+    }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum MctsState {
     Win,
     Lose,
@@ -18,72 +53,282 @@ pub enum MctsState {
     Unfinished,
 }
 
+impl MctsState {
+    /// Swaps `Win` and `Lose`, leaving `Draw`/`Unfinished` unchanged. Used to
+    /// re-express a proven outcome stored from the root player's perspective
+    /// in terms of whoever is to move at a given node (or back again, since
+    /// the swap is its own inverse).
+    fn flipped(self) -> Self {
+        match self {
+            MctsState::Win => MctsState::Lose,
+            MctsState::Lose => MctsState::Win,
+            other => other,
+        }
+    }
+}
+
 pub struct Mcts<Game: MctsGame> {
     tree: Tree<MctsNode<Game>>,
     player: Game::Player,
+    exploration: f32,
+    rng: StdRng,
+    transposition: Option<HashMap<u64, Stats>>,
+    rollout_depth: Option<u32>,
 }
 
-impl<Game: MctsGame + std::fmt::Debug> Mcts<Game> {
-    const EXPLORATION: f32 = core::f32::consts::SQRT_2;
+/// Builder for [`Mcts`], letting callers tune the UCB1 exploration constant
+/// and seed the random number generator for reproducible runs.
+pub struct MctsBuilder<Game: MctsGame> {
+    game: Game,
+    exploration: f32,
+    seed: Option<u64>,
+    use_transposition: bool,
+    rollout_depth: Option<u32>,
+}
 
-    pub fn new(game: Game) -> Self {
+impl<Game: MctsGame> MctsBuilder<Game> {
+    fn new(game: Game) -> Self {
         Self {
-            player: game.player(),
-            tree: Tree::new(MctsNode::new(game, None)),
+            game,
+            exploration: DEFAULT_EXPLORATION,
+            seed: None,
+            use_transposition: false,
+            rollout_depth: None,
         }
     }
 
+    /// Sets the UCB1 exploration constant (`C` in `score + C * sqrt(ln(N) / n)`).
+    pub fn exploration(mut self, exploration: f32) -> Self {
+        self.exploration = exploration;
+        self
+    }
+
+    /// Seeds the random number generator used for expansion and simulation,
+    /// making the search deterministic.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Enables the transposition table: nodes whose `Game::state_key` matches
+    /// share a single `Stats` entry instead of each accumulating its own.
+    /// Requires `Game::state_key` to be overridden to return `Some(key)`;
+    /// searching with this on and a game that still returns `None` panics,
+    /// since silently treating every node as one shared key would corrupt
+    /// the search. Note that back-propagation still only walks the current
+    /// node's parent chain (this is a tree, not a DAG): every node sharing a
+    /// key with a node on that chain reads the updated entry, but the other
+    /// parents of a transposed node (reached by a different move order) are
+    /// not updated by the playout.
+    pub fn use_transposition(mut self, use_transposition: bool) -> Self {
+        self.use_transposition = use_transposition;
+        self
+    }
+
+    /// Caps simulations at `depth` random plies; if no terminal state is
+    /// reached by then, `Game::evaluate` is used as the simulation's value
+    /// instead of continuing the rollout to completion.
+    pub fn rollout_depth(mut self, depth: u32) -> Self {
+        self.rollout_depth = Some(depth);
+        self
+    }
+
+    pub fn build(self) -> Mcts<Game> {
+        let rng = match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+        Mcts {
+            player: self.game.player(),
+            tree: Tree::new(MctsNode::new(self.game, None)),
+            exploration: self.exploration,
+            rng,
+            transposition: self.use_transposition.then(HashMap::new),
+            rollout_depth: self.rollout_depth,
+        }
+    }
+}
+
+impl<Game: MctsGame + std::fmt::Debug> Mcts<Game> {
+    /// Number of playouts between checks of the wall clock in `search_until`,
+    /// so the deadline check doesn't pay for an `Instant::now()` every iteration.
+    const CLOCK_CHECK_INTERVAL: u64 = 128;
+
+    pub fn new(game: Game) -> Self {
+        Self::builder(game).build()
+    }
+
+    /// Returns a builder for configuring the exploration constant and RNG
+    /// seed before constructing the search tree.
+    pub fn builder(game: Game) -> MctsBuilder<Game> {
+        MctsBuilder::new(game)
+    }
+
     pub fn search(&mut self, iterations: u64) {
         for _ in 0..iterations {
-            let leaf = self.select_leaf();
-            let new_leaf = self.expand(leaf).unwrap_or(leaf);
-            let state = self.simulate(new_leaf);
-            self.back_propagate(new_leaf, state);
+            self.playout();
         }
         // println!("{:?}", self.tree);
     }
 
+    /// Runs the select/expand/simulate/back-propagate cycle until `deadline`
+    /// has passed, for use cases with a real-time move budget rather than a
+    /// fixed playout count. The clock is only checked every
+    /// `CLOCK_CHECK_INTERVAL` playouts to amortize the cost of `Instant::now()`.
+    pub fn search_until(&mut self, deadline: Instant) {
+        let mut i: u64 = 0;
+        loop {
+            if i.is_multiple_of(Self::CLOCK_CHECK_INTERVAL) && Instant::now() >= deadline {
+                break;
+            }
+            self.playout();
+            i += 1;
+        }
+    }
+
+    /// Runs the search for `budget`, i.e. until `Instant::now() + budget`.
+    pub fn search_for(&mut self, budget: Duration) {
+        self.search_until(Instant::now() + budget);
+    }
+
+    /// Runs a single select/expand/simulate/back-propagate cycle.
+    fn playout(&mut self) {
+        let leaf = self.select_leaf();
+        let new_leaf = self.expand(leaf).unwrap_or(leaf);
+        let (value, solved) = self.simulate(new_leaf);
+        if let Some(state) = solved {
+            unsafe { self.tree.get_unchecked_mut(new_leaf) }.value().solved = Some(state);
+        }
+        self.back_propagate(new_leaf, value);
+    }
+
+    /// Returns the best action found so far. A child proven to be a forced
+    /// win for the root's mover is always preferred over an unproven one,
+    /// even if sampling hasn't yet driven its average score to the top.
     pub fn best_action(&self) -> (Game::Action, f32) {
+        let mover = self.tree.root().value().game().player();
         let mut best_score = f32::NEG_INFINITY;
         let mut best_child = None;
+        let mut best_is_proven_win = false;
 
         let mut child = self.tree.root().first_child().map(|x| x.id());
         while let Some(next_child) = child {
-            let score = unsafe { self.tree.get_unchecked(next_child) }
-                .value()
-                .score(self.player);
-            if score > best_score {
+            let node = unsafe { self.tree.get_unchecked(next_child) }.value();
+            let is_proven_win = node
+                .solved
+                .is_some_and(|state| self.outcome_for(state, mover) == MctsState::Win);
+            let score = self.score(next_child);
+            let better = match (is_proven_win, best_is_proven_win) {
+                (true, false) => true,
+                (false, true) => false,
+                _ => score > best_score,
+            };
+            if better {
                 best_score = score;
                 best_child = child;
+                best_is_proven_win = is_proven_win;
             }
             child = unsafe { self.tree.get_unchecked(next_child) }
                 .next_sibling()
                 .map(|x| x.id());
         }
-        let node = unsafe { self.tree.get_unchecked(best_child.unwrap()) }.value();
-        (node.last_action().unwrap(), node.score(self.player))
+        let best_child = best_child.unwrap();
+        let node = unsafe { self.tree.get_unchecked(best_child) }.value();
+        (node.last_action().unwrap(), self.score(best_child))
     }
 
-    fn win(&mut self, node_id: NodeId) {
-        unsafe { self.tree.get_unchecked_mut(node_id) }.value().won += 1;
+    /// Returns the full first-ply breakdown: each root child's action,
+    /// score and playout count, for rendering move rankings, computing a
+    /// visit-count policy distribution, or picking the most-visited
+    /// ("robust child") move instead of the highest-scoring one.
+    pub fn ply_stats(&self) -> Vec<(Game::Action, f32, u32)> {
+        let mut stats = Vec::new();
+        self.for_each_ply(|action, score, playouts| stats.push((action, score, playouts)));
+        stats
     }
 
-    fn lose(&mut self, node_id: NodeId) {
-        unsafe { self.tree.get_unchecked_mut(node_id) }.value().lost += 1;
+    /// Calls `f` with each root child's action, score and playout count.
+    pub fn for_each_ply(&self, mut f: impl FnMut(Game::Action, f32, u32)) {
+        let mut child = self.tree.root().first_child().map(|x| x.id());
+        while let Some(next_child) = child {
+            let node = unsafe { self.tree.get_unchecked(next_child) }.value();
+            let playouts = self.stats(next_child).played();
+            f(node.last_action().unwrap(), self.score(next_child), playouts);
+            child = unsafe { self.tree.get_unchecked(next_child) }
+                .next_sibling()
+                .map(|x| x.id());
+        }
     }
 
-    fn draw(&mut self, node_id: NodeId) {
-        unsafe { self.tree.get_unchecked_mut(node_id) }
-            .value()
-            .drawn += 1;
+    /// Statistics for `node_id`: its own accumulated stats, or the shared
+    /// transposition-table entry for its state if transposition mode is on.
+    fn stats(&self, node_id: NodeId) -> Stats {
+        let node = unsafe { self.tree.get_unchecked(node_id) }.value();
+        match &self.transposition {
+            Some(table) => table.get(&Self::transposition_key(node.game())).copied().unwrap_or_default(),
+            None => node.stats,
+        }
     }
 
+    /// Unwraps `game`'s transposition key, panicking if `Game::state_key`
+    /// was never overridden. Only called once transposition mode is
+    /// actually on, so games that don't use transpositions never pay for
+    /// (or need to satisfy) this.
+    fn transposition_key(game: &Game) -> u64 {
+        game.state_key().expect(
+            "Game::state_key must be overridden to return Some(key) to use \
+             MctsBuilder::use_transposition(true)",
+        )
+    }
+
+    /// Re-expresses a solved `state` (stored from `self.player`'s point of
+    /// view) in terms of whether it's a win, loss or draw for `mover`. The
+    /// mapping is its own inverse, so the same call also converts the other
+    /// way around.
+    fn outcome_for(&self, state: MctsState, mover: Game::Player) -> MctsState {
+        if mover == self.player {
+            state
+        } else {
+            state.flipped()
+        }
+    }
+
+    fn score(&self, node_id: NodeId) -> f32 {
+        let node = unsafe { self.tree.get_unchecked(node_id) }.value();
+        let stats = self.stats(node_id);
+        let score = stats.value_sum / stats.played() as f32;
+        if self.player == node.game().player() {
+            score
+        } else {
+            1f32 - score
+        }
+    }
+
+    fn update_stats(&mut self, node_id: NodeId, f: impl Fn(&mut Stats)) {
+        if let Some(table) = &mut self.transposition {
+            let key = Self::transposition_key(unsafe { self.tree.get_unchecked(node_id) }.value().game());
+            f(table.entry(key).or_default());
+        }
+        f(&mut unsafe { self.tree.get_unchecked_mut(node_id) }.value().stats);
+    }
+
+    /// UCB1 score for `node`, except a node proven to be a forced win or
+    /// loss for the parent's mover short-circuits to +/- infinity so the
+    /// search locks onto forced wins and prunes refuted lines outright.
     fn selection_score(&self, node: NodeId) -> f32 {
-        let node = unsafe { self.tree.get_unchecked(node) };
-        let parent = node.parent().unwrap().value();
-        let node = node.value();
-        node.score(self.player)
-            + Self::EXPLORATION * f32::sqrt(f32::ln(parent.played() as f32) / node.played() as f32)
+        let parent = unsafe { self.tree.get_unchecked(node) }.parent().unwrap().id();
+        let mover = unsafe { self.tree.get_unchecked(parent) }.value().game().player();
+        if let Some(state) = unsafe { self.tree.get_unchecked(node) }.value().solved {
+            match self.outcome_for(state, mover) {
+                MctsState::Win => return f32::INFINITY,
+                MctsState::Lose => return f32::NEG_INFINITY,
+                MctsState::Draw | MctsState::Unfinished => {}
+            }
+        }
+        let node_played = self.stats(node).played();
+        let parent_played = self.stats(parent).played();
+        self.score(node)
+            + self.exploration * f32::sqrt(f32::ln(parent_played as f32) / node_played as f32)
     }
 
     fn select_leaf(&mut self) -> NodeId {
@@ -96,7 +341,11 @@ impl<Game: MctsGame + std::fmt::Debug> Mcts<Game> {
                 .map(|x| x.id());
             while child_id.is_some() {
                 let score = self.selection_score(child_id.unwrap());
-                if score > best_score {
+                // `>=` (not `>`) so the first child is always accepted even
+                // when every candidate is a proven loss (`NEG_INFINITY`),
+                // where `best_score` starting at `NEG_INFINITY` would
+                // otherwise leave `best_child_id` unset.
+                if score >= best_score {
                     best_score = score;
                     best_child_id = child_id;
                 }
@@ -117,11 +366,7 @@ impl<Game: MctsGame + std::fmt::Debug> Mcts<Game> {
             return None;
         }
         let len = actions.len();
-        let index = if len == 1 {
-            0
-        } else {
-            rand::random::<usize>() % len
-        };
+        let index = if len == 1 { 0 } else { self.rng.gen_range(0..len) };
         let action = actions.swap_remove(index);
         game.play(action);
         Some(
@@ -131,43 +376,104 @@ impl<Game: MctsGame + std::fmt::Debug> Mcts<Game> {
         )
     }
 
-    fn back_propagate(&mut self, leaf: NodeId, state: MctsState) {
-        let f = match state {
-            MctsState::Unfinished => unreachable!(),
-            MctsState::Win => Self::win,
-            MctsState::Lose => Self::lose,
-            MctsState::Draw => Self::draw,
-        };
+    fn back_propagate(&mut self, leaf: NodeId, value: f32) {
         let mut node = Some(leaf);
         while let Some(next_node) = node {
-            f(self, next_node);
+            self.update_stats(next_node, |stats| {
+                stats.value_sum += value;
+                stats.visits += 1;
+            });
+            self.update_solved(next_node);
             node = unsafe { self.tree.get_unchecked(next_node) }
                 .parent()
                 .map(|x| x.id());
         }
     }
 
-    fn simulate(&mut self, leaf: NodeId) -> MctsState {
+    /// Re-derives `node_id`'s solved status from its children, per the
+    /// MCTS-Solver rule: the player to move at `node_id` is proven to win as
+    /// soon as one child is a proven win for them, proven to lose only once
+    /// every *legal* child is a proven loss for them (i.e. every action has
+    /// been tried, not just every child expanded so far), and otherwise
+    /// proven to draw once every legal child is resolved with no win
+    /// available. Leaves solved status untouched for nodes without children
+    /// (their status, if any, was set directly from a terminal simulation).
+    fn update_solved(&mut self, node_id: NodeId) {
+        let node = unsafe { self.tree.get_unchecked(node_id) };
+        if !node.has_children() {
+            return;
+        }
+        let mover = node.value().game().player();
+        let untried_actions = !node.value().actions().is_empty();
+        let mut any_win = false;
+        let mut all_lose = true;
+        let mut all_resolved = !untried_actions;
+        let mut child = node.first_child().map(|x| x.id());
+        while let Some(child_id) = child {
+            let child_node = unsafe { self.tree.get_unchecked(child_id) };
+            match child_node.value().solved {
+                Some(state) => match self.outcome_for(state, mover) {
+                    MctsState::Win => any_win = true,
+                    MctsState::Lose => {}
+                    MctsState::Draw | MctsState::Unfinished => all_lose = false,
+                },
+                None => {
+                    all_resolved = false;
+                    all_lose = false;
+                }
+            }
+            child = child_node.next_sibling().map(|x| x.id());
+        }
+        let all_lose = all_lose && !untried_actions;
+        let solved = if any_win {
+            Some(MctsState::Win)
+        } else if all_lose {
+            Some(MctsState::Lose)
+        } else if all_resolved {
+            Some(MctsState::Draw)
+        } else {
+            None
+        };
+        if let Some(state) = solved {
+            let state = self.outcome_for(state, mover);
+            unsafe { self.tree.get_unchecked_mut(node_id) }.value().solved = Some(state);
+        }
+    }
+
+    /// Plays the game out at random from `leaf` and returns the resulting
+    /// value from `self.player`'s perspective (1 = win, 0.5 = draw, 0 =
+    /// loss), plus the proven outcome if `leaf` itself (before any random
+    /// moves) was already terminal. If `rollout_depth` is set and no
+    /// terminal state is reached within that many plies, `Game::evaluate` is
+    /// used for the value and no outcome is proven.
+    fn simulate(&mut self, leaf: NodeId) -> (f32, Option<MctsState>) {
         let mut game = unsafe { self.tree.get_unchecked(leaf) }
             .value()
             .game()
             .clone();
+        let mut depth = 0u32;
         loop {
             let state = game.state(self.player);
             match state {
+                MctsState::Win | MctsState::Lose | MctsState::Draw => {
+                    let value = match state {
+                        MctsState::Win => 1f32,
+                        MctsState::Lose => 0f32,
+                        MctsState::Draw => 0.5f32,
+                        MctsState::Unfinished => unreachable!(),
+                    };
+                    return (value, (depth == 0).then_some(state));
+                }
                 MctsState::Unfinished => {
+                    if self.rollout_depth.is_some_and(|max_depth| depth >= max_depth) {
+                        return (game.evaluate(self.player), None);
+                    }
                     let actions = game.legal_actions();
                     let len = actions.len();
-                    let index = if len == 1 {
-                        0
-                    } else {
-                        rand::random::<usize>() % len
-                    };
+                    let index = if len == 1 { 0 } else { self.rng.gen_range(0..len) };
                     let action = actions[index];
                     game.play(action);
-                }
-                state => {
-                    return state;
+                    depth += 1;
                 }
             }
         }
@@ -183,14 +489,34 @@ impl<Game: MctsGame + std::fmt::Debug> Mcts<Game> {
     }
 }
 
+/// Accumulated simulation value for a node, or for a transposition-table
+/// entry shared by every node reached with the same `Game::state_key`.
+/// `value_sum` is the sum of per-visit values in `[0, 1]` (1 = win, 0.5 =
+/// draw or heuristic midpoint, 0 = loss), so `value_sum / visits` is the
+/// node's average score.
+#[derive(Debug, Default, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct Stats {
+    value_sum: f32,
+    visits: u32,
+}
+
+impl Stats {
+    fn played(&self) -> u32 {
+        self.visits
+    }
+}
+
 #[derive(Debug)]
 struct MctsNode<Game: MctsGame> {
     game: Game,
     last_action: Option<Game::Action>,
     actions: Vec<Game::Action>,
-    won: u32,
-    lost: u32,
-    drawn: u32,
+    stats: Stats,
+    /// Proven outcome from the root player's (`Mcts::player`'s) point of view, set once a
+    /// terminal state or a fully-resolved set of children makes the node's
+    /// value exact rather than a UCB1 estimate. See `Mcts::update_solved`.
+    solved: Option<MctsState>,
 }
 
 impl<Game: MctsGame> MctsNode<Game> {
@@ -199,9 +525,8 @@ impl<Game: MctsGame> MctsNode<Game> {
             actions: game.legal_actions(),
             game,
             last_action,
-            won: 0,
-            lost: 0,
-            drawn: 0,
+            stats: Stats::default(),
+            solved: None,
         }
     }
 
@@ -220,17 +545,608 @@ impl<Game: MctsGame> MctsNode<Game> {
     fn last_action(&self) -> Option<Game::Action> {
         self.last_action
     }
+}
 
-    fn score(&self, player: Game::Player) -> f32 {
-        let score = (2 * self.won + self.drawn) as f32 / (2 * self.played()) as f32;
-        if player == self.game().player() {
-            score
-        } else {
-            1f32 - score
+/// Flattened, serializable form of one [`MctsNode`], used by
+/// [`Mcts::to_bytes`]/[`Mcts::from_bytes`] since `ego_tree::Tree` doesn't
+/// implement `Serialize`/`Deserialize` itself. `parent` is the index of
+/// this node's parent in the enclosing `Vec`, or `None` for the root. `actions` (the
+/// still-unexpanded legal actions) is persisted alongside `last_action`
+/// rather than recomputed from `Game::legal_actions` on load, so a resumed
+/// search doesn't re-offer actions that already have a child.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct NodeRecord<Action> {
+    parent: Option<usize>,
+    last_action: Option<Action>,
+    actions: Vec<Action>,
+    stats: Stats,
+    solved: Option<MctsState>,
+}
+
+/// On-disk representation of a whole [`Mcts`] search tree: the root game
+/// state plus every node flattened into a `Vec` in parent-before-child
+/// order. The root player is recovered from `root_game.player()` on load.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "Game: Serialize, Game::Action: Serialize",
+    deserialize = "Game: Deserialize<'de>, Game::Action: Deserialize<'de>"
+))]
+struct MctsRecord<Game: MctsGame> {
+    root_game: Game,
+    nodes: Vec<NodeRecord<Game::Action>>,
+}
+
+#[cfg(feature = "serde")]
+impl<Game> Mcts<Game>
+where
+    Game: MctsGame + std::fmt::Debug + Serialize + for<'de> Deserialize<'de>,
+    Game::Action: Serialize + for<'de> Deserialize<'de>,
+{
+    /// Serializes the accumulated search tree (game states, stats and
+    /// solved status) to bytes, so it can be reloaded with `from_bytes` to
+    /// share an opening book or continue a long search across restarts.
+    /// The exploration constant, RNG seed and transposition/rollout
+    /// settings are not persisted: a tree loaded with `from_bytes` always
+    /// uses the default exploration constant, an entropy-seeded RNG, no
+    /// transposition table and no rollout depth cap, and there is no method
+    /// to change these on the loaded `Mcts`.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, bincode::Error> {
+        let mut nodes = Vec::new();
+        let mut stack = vec![(self.tree.root().id(), None)];
+        while let Some((node_id, parent)) = stack.pop() {
+            let index = nodes.len();
+            let node = unsafe { self.tree.get_unchecked(node_id) };
+            nodes.push(NodeRecord {
+                parent,
+                last_action: node.value().last_action(),
+                actions: node.value().actions().to_vec(),
+                stats: node.value().stats,
+                solved: node.value().solved,
+            });
+            let mut child = node.first_child().map(|x| x.id());
+            while let Some(child_id) = child {
+                stack.push((child_id, Some(index)));
+                child = unsafe { self.tree.get_unchecked(child_id) }
+                    .next_sibling()
+                    .map(|x| x.id());
+            }
         }
+        let root_game = unsafe { self.tree.get_unchecked(self.tree.root().id()) }
+            .value()
+            .game()
+            .clone();
+        bincode::serialize(&MctsRecord { root_game, nodes })
     }
 
-    fn played(&self) -> u32 {
-        self.won + self.lost + self.drawn
+    /// Rebuilds a search tree previously saved with `to_bytes`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Mcts<Game>, bincode::Error> {
+        let record: MctsRecord<Game> = bincode::deserialize(bytes)?;
+        let player = record.root_game.player();
+        let mut tree = Tree::new(MctsNode::new(record.root_game, None));
+        let mut ids = Vec::with_capacity(record.nodes.len());
+        for (index, node_record) in record.nodes.into_iter().enumerate() {
+            let id = if index == 0 {
+                tree.root().id()
+            } else {
+                let parent_id = ids[node_record.parent.expect("non-root node needs a parent")];
+                let mut game = unsafe { tree.get_unchecked(parent_id) }.value().game().clone();
+                let action = node_record
+                    .last_action
+                    .expect("non-root node needs a last_action");
+                game.play(action);
+                unsafe { tree.get_unchecked_mut(parent_id) }
+                    .append(MctsNode::new(game, Some(action)))
+                    .id()
+            };
+            let mut node = unsafe { tree.get_unchecked_mut(id) };
+            let node = node.value();
+            *node.actions_mut() = node_record.actions;
+            node.stats = node_record.stats;
+            node.solved = node_record.solved;
+            ids.push(id);
+        }
+        Ok(Mcts {
+            player,
+            tree,
+            exploration: DEFAULT_EXPLORATION,
+            rng: StdRng::from_entropy(),
+            transposition: None,
+            rollout_depth: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A single-branch game where every ply has exactly one legal action,
+    /// ending in a forced loss after `LOSS_DEPTH` plies.
+    #[derive(Debug, Clone)]
+    struct ForcedLossGame {
+        depth: u8,
+    }
+
+    impl ForcedLossGame {
+        const LOSS_DEPTH: u8 = 3;
+    }
+
+    impl MctsGame for ForcedLossGame {
+        type Action = u8;
+        type Player = u8;
+
+        fn legal_actions(&self) -> Vec<u8> {
+            if self.depth < Self::LOSS_DEPTH {
+                vec![0]
+            } else {
+                vec![]
+            }
+        }
+
+        fn play(&mut self, _action: u8) {
+            self.depth += 1;
+        }
+
+        fn player(&self) -> u8 {
+            0
+        }
+
+        fn state(&self, _player: u8) -> MctsState {
+            if self.depth >= Self::LOSS_DEPTH {
+                MctsState::Lose
+            } else {
+                MctsState::Unfinished
+            }
+        }
+    }
+
+    #[test]
+    fn search_does_not_panic_when_every_line_is_a_proven_loss() {
+        let mut mcts = Mcts::new(ForcedLossGame { depth: 0 });
+        mcts.search(64);
+    }
+
+    #[test]
+    fn search_until_runs_no_playouts_once_the_deadline_has_already_passed() {
+        let mut mcts = Mcts::new(ForcedLossGame { depth: 0 });
+        mcts.search_until(Instant::now() - Duration::from_secs(1));
+        assert!(mcts.ply_stats().is_empty(), "a past deadline should stop before the first playout");
+    }
+
+    #[test]
+    fn search_for_performs_playouts_within_its_budget() {
+        let mut mcts = Mcts::new(ForcedLossGame { depth: 0 });
+        mcts.search_for(Duration::from_millis(50));
+        let stats = mcts.ply_stats();
+        assert_eq!(stats.len(), 1);
+        assert!(stats[0].2 > 0, "a generous budget should have run at least one playout");
+    }
+
+    /// A game with real branching (three actions per ply, always drawn at
+    /// depth 3) used to exercise the RNG and exploration constant, where a
+    /// single-action game wouldn't give the RNG anything to choose between.
+    #[derive(Debug, Clone)]
+    struct BranchingGame {
+        depth: u8,
+    }
+
+    impl MctsGame for BranchingGame {
+        type Action = u8;
+        type Player = u8;
+
+        fn legal_actions(&self) -> Vec<u8> {
+            if self.depth < 3 {
+                vec![0, 1, 2]
+            } else {
+                vec![]
+            }
+        }
+
+        fn play(&mut self, _action: u8) {
+            self.depth += 1;
+        }
+
+        fn player(&self) -> u8 {
+            0
+        }
+
+        fn state(&self, _player: u8) -> MctsState {
+            if self.depth >= 3 {
+                MctsState::Draw
+            } else {
+                MctsState::Unfinished
+            }
+        }
+    }
+
+    #[test]
+    fn seeded_rng_reproduces_the_same_search() {
+        let build = || Mcts::builder(BranchingGame { depth: 0 }).seed(42).build();
+        let mut a = build();
+        let mut b = build();
+        a.search(100);
+        b.search(100);
+        assert_eq!(a.ply_stats(), b.ply_stats());
+    }
+
+    #[test]
+    fn exploration_constant_affects_how_evenly_playouts_spread_across_children() {
+        let max_playouts = |exploration: f32| {
+            let mut mcts = Mcts::builder(BranchingGame { depth: 0 })
+                .seed(7)
+                .exploration(exploration)
+                .build();
+            mcts.search(30);
+            mcts.ply_stats().into_iter().map(|(_, _, playouts)| playouts).max().unwrap()
+        };
+        let greedy = max_playouts(0.0);
+        let explorative = max_playouts(2.0);
+        assert!(
+            explorative < greedy,
+            "a larger exploration constant should spread playouts more evenly across \
+             children (max child playouts: greedy={greedy}, explorative={explorative})"
+        );
+    }
+
+    /// A game that never reaches a terminal state, so with `rollout_depth`
+    /// set the only way `simulate` can return is via the cutoff. `evaluate`
+    /// reports how many plies have been played, which pins down exactly how
+    /// many random plies the rollout played before falling back to it.
+    #[derive(Debug, Clone)]
+    struct RolloutDepthGame {
+        depth: u8,
+    }
+
+    impl MctsGame for RolloutDepthGame {
+        type Action = u8;
+        type Player = u8;
+
+        fn legal_actions(&self) -> Vec<u8> {
+            vec![0]
+        }
+
+        fn play(&mut self, _action: u8) {
+            self.depth += 1;
+        }
+
+        fn player(&self) -> u8 {
+            0
+        }
+
+        fn state(&self, _player: u8) -> MctsState {
+            MctsState::Unfinished
+        }
+
+        fn evaluate(&self, _player: u8) -> f32 {
+            self.depth as f32
+        }
+    }
+
+    #[test]
+    fn rollout_depth_cuts_the_simulation_off_at_exactly_the_configured_depth() {
+        const ROLLOUT_DEPTH: u32 = 5;
+        let mut mcts = Mcts::builder(RolloutDepthGame { depth: 0 })
+            .rollout_depth(ROLLOUT_DEPTH)
+            .build();
+        mcts.search(1);
+
+        let stats = mcts.ply_stats();
+        assert_eq!(stats.len(), 1);
+        let (_, score, playouts) = stats[0];
+        assert_eq!(playouts, 1);
+        // One ply from expand() plus ROLLOUT_DEPTH random plies from
+        // simulate()'s rollout before it falls back to evaluate().
+        assert_eq!(score, (1 + ROLLOUT_DEPTH) as f32);
+    }
+
+    /// Two root actions: `0` leads to a node `P` with two actions, one an
+    /// immediate loss and one an immediate win (for the single player);
+    /// `1` leads straight to a loss. Whichever of `P`'s two actions gets
+    /// tried first, the search must still find the win reachable through
+    /// the other one rather than declaring `P` (and thus the whole `0`
+    /// branch) a proven loss the moment the first child resolves.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    enum SolverStep {
+        Root,
+        P,
+        PLose,
+        PWin,
+        RootLose,
+    }
+
+    #[derive(Debug, Clone)]
+    struct SolverTestGame {
+        step: SolverStep,
+    }
+
+    impl MctsGame for SolverTestGame {
+        type Action = u8;
+        type Player = u8;
+
+        fn legal_actions(&self) -> Vec<u8> {
+            match self.step {
+                SolverStep::Root | SolverStep::P => vec![0, 1],
+                SolverStep::PLose | SolverStep::PWin | SolverStep::RootLose => vec![],
+            }
+        }
+
+        fn play(&mut self, action: u8) {
+            self.step = match (&self.step, action) {
+                (SolverStep::Root, 0) => SolverStep::P,
+                (SolverStep::Root, 1) => SolverStep::RootLose,
+                (SolverStep::P, 0) => SolverStep::PLose,
+                (SolverStep::P, 1) => SolverStep::PWin,
+                _ => unreachable!("no legal actions left"),
+            };
+        }
+
+        fn player(&self) -> u8 {
+            0
+        }
+
+        fn state(&self, _player: u8) -> MctsState {
+            match self.step {
+                SolverStep::PLose | SolverStep::RootLose => MctsState::Lose,
+                SolverStep::PWin => MctsState::Win,
+                SolverStep::Root | SolverStep::P => MctsState::Unfinished,
+            }
+        }
+    }
+
+    #[test]
+    fn solver_finds_a_win_behind_a_losing_sibling() {
+        // Try both seeds so the test doesn't depend on which of P's two
+        // actions the RNG happens to expand first.
+        for seed in [0, 1] {
+            let mut mcts = Mcts::builder(SolverTestGame { step: SolverStep::Root })
+                .seed(seed)
+                .build();
+            mcts.search(200);
+            let (action, _) = mcts.best_action();
+            assert_eq!(action, 0, "seed {seed}: root should prefer the branch with a win");
+        }
+    }
+
+    /// A single-ply game where action `0` wins and action `1` loses, used to
+    /// pin down exact per-child score/playout values for `ply_stats`.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    enum WinLoseStep {
+        Root,
+        Win,
+        Lose,
+    }
+
+    #[derive(Debug, Clone)]
+    struct WinLoseGame {
+        step: WinLoseStep,
+    }
+
+    impl MctsGame for WinLoseGame {
+        type Action = u8;
+        type Player = u8;
+
+        fn legal_actions(&self) -> Vec<u8> {
+            if self.step == WinLoseStep::Root {
+                vec![0, 1]
+            } else {
+                vec![]
+            }
+        }
+
+        fn play(&mut self, action: u8) {
+            self.step = if action == 0 { WinLoseStep::Win } else { WinLoseStep::Lose };
+        }
+
+        fn player(&self) -> u8 {
+            0
+        }
+
+        fn state(&self, _player: u8) -> MctsState {
+            match self.step {
+                WinLoseStep::Win => MctsState::Win,
+                WinLoseStep::Lose => MctsState::Lose,
+                WinLoseStep::Root => MctsState::Unfinished,
+            }
+        }
+    }
+
+    #[test]
+    fn ply_stats_reports_each_root_childs_action_score_and_playouts() {
+        let mut mcts = Mcts::new(WinLoseGame { step: WinLoseStep::Root });
+        mcts.search(2);
+        let mut stats = mcts.ply_stats();
+        stats.sort_by_key(|(action, _, _)| *action);
+        assert_eq!(stats, vec![(0, 1.0, 1), (1, 0.0, 1)]);
+    }
+
+    #[test]
+    fn for_each_ply_matches_ply_stats() {
+        let mut mcts = Mcts::new(WinLoseGame { step: WinLoseStep::Root });
+        mcts.search(2);
+
+        let mut via_callback = Vec::new();
+        mcts.for_each_ply(|action, score, playouts| via_callback.push((action, score, playouts)));
+        via_callback.sort_by_key(|(action, _, _)| *action);
+
+        let mut stats = mcts.ply_stats();
+        stats.sort_by_key(|(action, _, _)| *action);
+
+        assert_eq!(via_callback, stats);
+    }
+
+    #[cfg(feature = "serde")]
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct CounterGame {
+        value: u8,
+    }
+
+    #[cfg(feature = "serde")]
+    impl MctsGame for CounterGame {
+        type Action = u8;
+        type Player = u8;
+
+        fn legal_actions(&self) -> Vec<u8> {
+            if self.value < 2 {
+                vec![0, 1]
+            } else {
+                vec![]
+            }
+        }
+
+        fn play(&mut self, action: u8) {
+            self.value += action;
+        }
+
+        fn player(&self) -> u8 {
+            0
+        }
+
+        fn state(&self, _player: u8) -> MctsState {
+            if self.value >= 2 {
+                MctsState::Draw
+            } else {
+                MctsState::Unfinished
+            }
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn to_bytes_from_bytes_round_trips_the_tree() {
+        let mut mcts = Mcts::new(CounterGame { value: 0 });
+        mcts.search(100);
+        let mut expected = mcts.ply_stats();
+        expected.sort_by_key(|(action, _, _)| *action);
+
+        let bytes = mcts.to_bytes().expect("serialize");
+        let restored: Mcts<CounterGame> = Mcts::from_bytes(&bytes).expect("deserialize");
+        let mut actual = restored.ply_stats();
+        actual.sort_by_key(|(action, _, _)| *action);
+
+        assert_eq!(actual, expected);
+    }
+
+    /// Root has two actions that both transition into the *same*
+    /// transposition key (`Shared`), which then has a single action leading
+    /// to a terminal draw. With transposition mode on, the two root
+    /// children's reported stats come from one shared `Stats` entry, so
+    /// they must always be identical, however the search happened to split
+    /// playouts between them.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    enum TransStep {
+        Root,
+        Shared,
+        Done,
+    }
+
+    #[derive(Debug, Clone)]
+    struct TransGame {
+        step: TransStep,
+    }
+
+    impl MctsGame for TransGame {
+        type Action = u8;
+        type Player = u8;
+
+        fn legal_actions(&self) -> Vec<u8> {
+            match self.step {
+                TransStep::Root => vec![0, 1],
+                TransStep::Shared => vec![0],
+                TransStep::Done => vec![],
+            }
+        }
+
+        fn play(&mut self, _action: u8) {
+            self.step = match self.step {
+                TransStep::Root => TransStep::Shared,
+                TransStep::Shared => TransStep::Done,
+                TransStep::Done => unreachable!("no legal actions left"),
+            };
+        }
+
+        fn player(&self) -> u8 {
+            0
+        }
+
+        fn state(&self, _player: u8) -> MctsState {
+            if self.step == TransStep::Done {
+                MctsState::Draw
+            } else {
+                MctsState::Unfinished
+            }
+        }
+
+        fn state_key(&self) -> Option<u64> {
+            Some(match self.step {
+                TransStep::Root => 0,
+                TransStep::Shared => 1,
+                TransStep::Done => 2,
+            })
+        }
+    }
+
+    #[test]
+    fn transposition_shares_stats_across_siblings_with_the_same_key() {
+        let mut mcts = Mcts::builder(TransGame { step: TransStep::Root })
+            .seed(0)
+            .use_transposition(true)
+            .build();
+        mcts.search(50);
+
+        let stats = mcts.ply_stats();
+        assert_eq!(stats.len(), 2);
+        assert_eq!(
+            (stats[0].1, stats[0].2),
+            (stats[1].1, stats[1].2),
+            "siblings sharing a transposition key should report identical merged stats"
+        );
+        assert!(stats[0].2 > 0);
+    }
+
+    /// A game that never overrides `state_key`, so it keeps the trait's
+    /// default of `None`.
+    #[derive(Debug, Clone)]
+    struct UnkeyedGame {
+        done: bool,
+    }
+
+    impl MctsGame for UnkeyedGame {
+        type Action = u8;
+        type Player = u8;
+
+        fn legal_actions(&self) -> Vec<u8> {
+            if self.done {
+                vec![]
+            } else {
+                vec![0]
+            }
+        }
+
+        fn play(&mut self, _action: u8) {
+            self.done = true;
+        }
+
+        fn player(&self) -> u8 {
+            0
+        }
+
+        fn state(&self, _player: u8) -> MctsState {
+            if self.done {
+                MctsState::Draw
+            } else {
+                MctsState::Unfinished
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Game::state_key must be overridden")]
+    fn transposition_panics_without_an_overridden_state_key() {
+        let mut mcts = Mcts::builder(UnkeyedGame { done: false })
+            .use_transposition(true)
+            .build();
+        mcts.search(1);
     }
 }